@@ -1,35 +1,356 @@
 use super::*;
 
 use anyhow::{bail, Result};
-use crossterm::terminal;
+use crossterm::terminal::{self, Clear, ClearType};
+use crossterm::{cursor, queue};
+use rusqlite::{params, Connection};
+use serde::Deserialize;
 use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::config::input::Input;
 
 #[derive(Debug, Clone)]
 pub struct VsMode {
     pub models: Vec<Model>,
+    /// Model used to automatically rank responses instead of a manual prompt
+    pub judge: Option<Model>,
+    /// Model used to merge several hand-picked responses into one answer
+    pub synth: Option<Model>,
 }
 
-type VsResponse = (usize, String, Result<String, anyhow::Error>);
+/// Display index, model id, result, and wall-clock latency in milliseconds
+type VsResponse = (usize, String, Result<String, anyhow::Error>, u128);
 
-/// Prepare input with the specified model's role
-fn prepare_input_with_model(input: &Input, model: &Model) -> Input {
+/// Verdict returned by the judge model, parsed from its JSON response
+#[derive(Debug, Deserialize)]
+struct JudgeVerdict {
+    winner: usize,
+    ranking: Vec<usize>,
+    rationale: String,
+}
+
+/// A past VS round as recorded in the local history store
+#[derive(Debug, Clone)]
+pub struct VsHistoryRound {
+    pub id: i64,
+    pub created_at: i64,
+    pub prompt: String,
+}
+
+/// A single model's recorded response within a past VS round
+#[derive(Debug, Clone)]
+pub struct VsHistoryResponse {
+    pub display_index: usize,
+    pub model_id: String,
+    pub text: Option<String>,
+    pub elapsed_ms: Option<i64>,
+    pub selected: bool,
+}
+
+fn vs_history_db_path() -> Result<PathBuf> {
+    let dir = dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("failed to resolve the user config directory"))?
+        .join("aichat");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir.join("vs_history.sqlite"))
+}
+
+/// Open (creating if needed) the local VS history database
+fn open_vs_history_db() -> Result<Connection> {
+    let conn = Connection::open(vs_history_db_path()?)?;
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS vs_rounds (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            created_at INTEGER NOT NULL,
+            prompt TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS vs_responses (
+            round_id INTEGER NOT NULL REFERENCES vs_rounds(id),
+            display_index INTEGER NOT NULL,
+            model_id TEXT NOT NULL,
+            text TEXT,
+            elapsed_ms INTEGER,
+            selected INTEGER NOT NULL DEFAULT 0
+        );",
+    )?;
+    Ok(conn)
+}
+
+/// Record a finished VS round and its responses, returning the new round id
+fn record_vs_round(prompt: &str, results: &[VsResponse]) -> Result<i64> {
+    let mut conn = open_vs_history_db()?;
+    let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+
+    let tx = conn.transaction()?;
+    tx.execute(
+        "INSERT INTO vs_rounds (created_at, prompt) VALUES (?1, ?2)",
+        params![created_at, prompt],
+    )?;
+    let round_id = tx.last_insert_rowid();
+
+    for (display_index, model_id, result, elapsed_ms) in results {
+        let text = match result {
+            Ok(text) => Some(text.clone()),
+            Err(e) => Some(format!("Error: {e}")),
+        };
+        tx.execute(
+            "INSERT INTO vs_responses (round_id, display_index, model_id, text, elapsed_ms, selected)
+             VALUES (?1, ?2, ?3, ?4, ?5, 0)",
+            params![round_id, *display_index as i64, model_id, text, *elapsed_ms as i64],
+        )?;
+    }
+
+    tx.commit()?;
+    Ok(round_id)
+}
+
+/// Mark the responses at the given display indices as the ones the user ultimately picked
+fn mark_vs_round_selected(round_id: i64, selected_display_indices: &[usize]) -> Result<()> {
+    let conn = open_vs_history_db()?;
+    for display_index in selected_display_indices {
+        conn.execute(
+            "UPDATE vs_responses SET selected = 1 WHERE round_id = ?1 AND display_index = ?2",
+            params![round_id, *display_index as i64],
+        )?;
+    }
+    Ok(())
+}
+
+/// List recorded VS rounds, most recent first
+pub fn list_vs_rounds(limit: usize) -> Result<Vec<VsHistoryRound>> {
+    let conn = open_vs_history_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT id, created_at, prompt FROM vs_rounds ORDER BY id DESC LIMIT ?1",
+    )?;
+    let rounds = stmt
+        .query_map(params![limit as i64], |row| {
+            Ok(VsHistoryRound {
+                id: row.get(0)?,
+                created_at: row.get(1)?,
+                prompt: row.get(2)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(rounds)
+}
+
+/// Load the responses recorded for a single VS round, in display order
+fn load_vs_round_responses(round_id: i64) -> Result<Vec<VsHistoryResponse>> {
+    let conn = open_vs_history_db()?;
+    let mut stmt = conn.prepare(
+        "SELECT display_index, model_id, text, elapsed_ms, selected
+         FROM vs_responses WHERE round_id = ?1 ORDER BY display_index",
+    )?;
+    let responses = stmt
+        .query_map(params![round_id], |row| {
+            Ok(VsHistoryResponse {
+                display_index: row.get::<_, i64>(0)? as usize,
+                model_id: row.get(1)?,
+                text: row.get(2)?,
+                elapsed_ms: row.get(3)?,
+                selected: row.get::<_, i64>(4)? != 0,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(responses)
+}
+
+/// Re-print a previously recorded VS round through `print_markdown`, for auditing past comparisons
+pub fn show_vs_round(config: &GlobalConfig, round_id: i64) -> Result<()> {
+    let conn = open_vs_history_db()?;
+    let prompt: String = conn.query_row(
+        "SELECT prompt FROM vs_rounds WHERE id = ?1",
+        params![round_id],
+        |row| row.get(0),
+    )?;
+
+    println!("Prompt: {prompt}");
+
+    for response in load_vs_round_responses(round_id)? {
+        let marker = if response.selected { " (selected)" } else { "" };
+        println!();
+        println!("--- [{}] {}{} ---", response.display_index, response.model_id, marker);
+        if let Some(text) = &response.text {
+            config.read().print_markdown(text)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A round of the VS REPL already settled: the prompt that was asked and the response
+/// (selected or synthesized) that was folded into conversation history
+type VsTurn = (String, String);
+
+/// Build the text actually sent to a model for this turn: prior VS REPL turns (if any),
+/// formatted as `User:`/`Assistant:` blocks, followed by the current prompt. Shared by
+/// `prepare_input_with_model` and the token/cost estimate in `print_vs_summary_table` so
+/// both agree on what a round actually cost.
+fn effective_prompt_text(input: &Input, history: &[VsTurn]) -> String {
+    if history.is_empty() {
+        return input.text().to_string();
+    }
+
+    let mut text = String::new();
+    for (prompt, response) in history {
+        text.push_str(&format!("User: {prompt}\nAssistant: {response}\n\n"));
+    }
+    text.push_str(&format!("User: {}", input.text()));
+    text
+}
+
+/// Prepare input with the specified model's role, injecting any prior VS REPL turns so the
+/// model debates with the full accumulated conversation rather than a single-shot query
+fn prepare_input_with_model(input: &Input, model: &Model, history: &[VsTurn]) -> Input {
     let mut model_input = input.clone();
     let mut role_with_new_model = model_input.role().clone();
     role_with_new_model.set_model(model.clone());
     model_input.set_role(role_with_new_model);
+
+    if !history.is_empty() {
+        model_input.set_text(effective_prompt_text(input, history));
+    }
+
     model_input
 }
 
 /// Query a single model and return the text response
-async fn query_model(input: Input, model: Model) -> Result<String> {
-    let model_input = prepare_input_with_model(&input, &model);
+async fn query_model(input: Input, model: Model, history: &[VsTurn]) -> Result<String> {
+    let model_input = prepare_input_with_model(&input, &model, history);
     let client = model_input.create_client()?;
     let output = client.chat_completions(model_input.clone()).await?;
     Ok(output.text)
 }
 
+/// Progress update from one model's streaming query, keyed by its spawn index
+enum VsStreamEvent {
+    /// A chunk of newly-generated text for the model at `index`
+    Chunk { index: usize, delta: String },
+    /// The model at `index` finished, successfully or not
+    Done {
+        index: usize,
+        model_id: String,
+        result: Result<String, anyhow::Error>,
+        elapsed_ms: u128,
+    },
+}
+
+/// Query a single model via the streaming completion path, forwarding each chunk over `tx`
+/// as it arrives and sending a final `Done` event with the fully assembled text
+async fn query_model_streaming(
+    input: Input,
+    model: Model,
+    index: usize,
+    tx: UnboundedSender<VsStreamEvent>,
+    history: Vec<VsTurn>,
+) {
+    let task_start = std::time::Instant::now();
+    let model_id = model.id().to_string();
+    let model_input = prepare_input_with_model(&input, &model, &history);
+
+    let result = async {
+        let client = model_input.create_client()?;
+        let mut buffer = String::new();
+        let chunk_tx = tx.clone();
+        client
+            .chat_completions_streaming(&model_input, &mut |delta: &str| {
+                buffer.push_str(delta);
+                let _ = chunk_tx.send(VsStreamEvent::Chunk {
+                    index,
+                    delta: delta.to_string(),
+                });
+                Ok(())
+            })
+            .await?;
+        Ok::<String, anyhow::Error>(buffer)
+    }
+    .await;
+
+    let elapsed_ms = task_start.elapsed().as_millis();
+    let _ = tx.send(VsStreamEvent::Done {
+        index,
+        model_id,
+        result,
+        elapsed_ms,
+    });
+}
+
+/// Build a derived input carrying different text but the same role/session context
+fn input_with_text(input: &Input, text: String) -> Input {
+    let mut derived = input.clone();
+    derived.set_text(text);
+    derived
+}
+
+/// Build the prompt asking the judge model to rank the successful VS responses
+fn build_judge_prompt(input: &Input, results: &[VsResponse]) -> String {
+    let mut prompt = format!(
+        "Original prompt:\n{}\n\nSeveral AI models answered the prompt above. Review each response and pick the best one.\n",
+        input.text()
+    );
+
+    for (display_index, model_id, result, _elapsed_ms) in results {
+        if let Ok(text) = result {
+            prompt.push_str(&format!("\n[{display_index}] ({model_id})\n{text}\n"));
+        }
+    }
+
+    prompt.push_str(
+        "\nRespond with strict JSON only, no surrounding prose or code fences, in the form:\n\
+        {\"winner\": <index>, \"ranking\": [<indices, best first>], \"rationale\": \"...\"}",
+    );
+
+    prompt
+}
+
+/// Extract the span from the first `{` to the last `}` in a string, tolerating surrounding
+/// prose. Braces are not matched, so text holding several objects yields all of them.
+fn extract_json_object(text: &str) -> Option<&str> {
+    let start = text.find('{')?;
+    let end = text.rfind('}')?;
+    (end > start).then(|| &text[start..=end])
+}
+
+/// Ask the judge model to pick a winner among the successful responses.
+/// Returns the winning response's display index, or `None` if there was nothing to judge.
+async fn run_judge(
+    config: &GlobalConfig,
+    judge: &Model,
+    input: &Input,
+    results: &[VsResponse],
+) -> Result<Option<usize>> {
+    if !results.iter().any(|(_, _, result, _)| result.is_ok()) {
+        return Ok(None);
+    }
+
+    let prompt = build_judge_prompt(input, results);
+    let judge_input = input_with_text(input, prompt);
+    let raw = query_model(judge_input, judge.clone(), &[]).await?;
+
+    let json = extract_json_object(&raw)
+        .ok_or_else(|| anyhow::anyhow!("judge response did not contain a JSON object"))?;
+    let verdict: JudgeVerdict = serde_json::from_str(json)?;
+
+    let winner_is_valid = results
+        .iter()
+        .any(|(idx, _, result, _)| *idx == verdict.winner && result.is_ok());
+    if !winner_is_valid {
+        bail!("judge picked an out-of-range or failed response: {}", verdict.winner);
+    }
+
+    let summary = format!(
+        "**Judge ranking:** {:?}\n\n**Rationale:**\n\n{}",
+        verdict.ranking, verdict.rationale
+    );
+    println!();
+    config.read().print_markdown(&summary)?;
+
+    Ok(Some(verdict.winner))
+}
+
 /// Print a model response header with terminal-width dashes
 fn print_response_header(index: usize, model_id: &str) {
     println!();
@@ -39,6 +360,49 @@ fn print_response_header(index: usize, model_id: &str) {
     println!("{}{}", header, "-".repeat(dash_count));
 }
 
+/// Estimate the dollar cost of a response from a model's configured pricing, if any
+fn estimate_cost(model: &Model, prompt_tokens: usize, completion_tokens: usize) -> Option<f64> {
+    let input_price = model.input_price()?;
+    let output_price = model.output_price()?;
+    Some((prompt_tokens as f64 * input_price + completion_tokens as f64 * output_price) / 1_000_000.0)
+}
+
+/// Print a terminal-width-aware table comparing tokens, cost, and latency across VS responses.
+/// `history` must be the same VS REPL history threaded through this round's queries, so the
+/// prompt token count matches what each model actually received, not just the raw input text.
+fn print_vs_summary_table(vs_mode: &VsMode, input: &Input, history: &[VsTurn], results: &[VsResponse]) {
+    println!();
+    let header = "--- Comparison ---";
+    let width = terminal::size().map(|(w, _)| w).unwrap_or(80) as usize;
+    let dash_count = width.saturating_sub(header.len());
+    println!("{}{}", header, "-".repeat(dash_count));
+
+    let prompt_tokens = crate::utils::count_tokens(&effective_prompt_text(input, history));
+
+    println!(
+        "{:<4} {:<24} {:>10} {:>10} {:>10} {:>10}",
+        "#", "model", "prompt_tk", "compl_tk", "cost", "latency"
+    );
+    for (display_index, model_id, result, elapsed_ms) in results {
+        let completion_tokens = match result {
+            Ok(text) => crate::utils::count_tokens(text),
+            Err(_) => 0,
+        };
+        let cost = vs_mode
+            .models
+            .iter()
+            .find(|model| model.id() == model_id)
+            .and_then(|model| estimate_cost(model, prompt_tokens, completion_tokens))
+            .map(|cost| format!("${cost:.4}"))
+            .unwrap_or_else(|| "-".to_string());
+
+        println!(
+            "{:<4} {:<24} {:>10} {:>10} {:>10} {:>9}ms",
+            display_index, model_id, prompt_tokens, completion_tokens, cost, elapsed_ms
+        );
+    }
+}
+
 /// Display a single model response or error
 fn display_response(config: &GlobalConfig, result: &Result<String, anyhow::Error>) -> Result<()> {
     match result {
@@ -75,12 +439,72 @@ fn parse_selection(input: &str, max_value: usize) -> Result<usize> {
     Ok(selection)
 }
 
-/// Initialize VS mode with the specified models
+/// Parse a selection that may name several responses at once, e.g. "1,3,4"
+fn parse_multi_selection(input: &str, max_value: usize) -> Result<Vec<usize>> {
+    let trimmed = input.trim();
+
+    if trimmed.eq_ignore_ascii_case("exit") || trimmed.eq_ignore_ascii_case("quit") {
+        std::process::exit(0);
+    }
+
+    let mut selections = Vec::new();
+    for part in trimmed.split(',') {
+        let selection: usize = part
+            .trim()
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid selection"))?;
+
+        if selection < 1 || selection > max_value {
+            bail!("Invalid selection");
+        }
+        selections.push(selection);
+    }
+
+    if selections.is_empty() {
+        bail!("Invalid selection");
+    }
+
+    Ok(selections)
+}
+
+/// Pull a `--flag <value>` pair out of a VS mode args string, leaving the rest behind.
+/// Matches `flag` as a whole whitespace-delimited token, not a raw substring, so a model id
+/// that happens to contain e.g. `--judge` as a substring isn't mistaken for the flag.
+fn extract_flag_value(args: &mut String, flag: &str) -> Option<String> {
+    let tokens: Vec<&str> = args.split_whitespace().collect();
+    let pos = tokens.iter().position(|t| *t == flag)?;
+    let has_value = tokens.get(pos + 1).is_some();
+    let value = tokens.get(pos + 1).copied().unwrap_or("").to_string();
+
+    let rest: Vec<&str> = tokens
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| *i != pos && !(has_value && *i == pos + 1))
+        .map(|(_, t)| t)
+        .collect();
+    *args = rest.join(" ");
+
+    Some(value)
+}
+
+/// Initialize VS mode with the specified models.
+/// `models_str` may carry a trailing `--judge <model_id>` to configure an
+/// arbiter model that auto-ranks responses instead of prompting the user, and/or
+/// a `--synth <model_id>` to configure a model that merges multi-selected responses.
 pub async fn vs_mode_init(
     config: &GlobalConfig,
     models_str: &str,
 ) -> Result<()> {
-    let models_list: Vec<&str> = models_str.split(',').map(|s| s.trim()).collect();
+    let mut remainder = models_str.to_string();
+    let judge_id = extract_flag_value(&mut remainder, "--judge");
+    let synth_id = extract_flag_value(&mut remainder, "--synth");
+
+    let models_list: Vec<&str> = remainder
+        .trim()
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
 
     if models_list.len() < 2 {
         bail!("VS mode requires at least 2 models");
@@ -92,38 +516,47 @@ pub async fn vs_mode_init(
         models.push(model);
     }
 
-    let vs_mode = VsMode {
-        models,
+    let judge = match judge_id {
+        Some(judge_id) if !judge_id.is_empty() => {
+            Some(Model::retrieve_model(&config.read(), &judge_id, crate::ModelType::Chat)?)
+        }
+        _ => None,
     };
 
-    config.write().vs_mode = Some(vs_mode);
-    println!("VS mode initialized with {} models", models_list.len());
+    let synth = match synth_id {
+        Some(synth_id) if !synth_id.is_empty() => {
+            Some(Model::retrieve_model(&config.read(), &synth_id, crate::ModelType::Chat)?)
+        }
+        _ => None,
+    };
 
-    Ok(())
-}
+    let vs_mode = VsMode { models, judge, synth };
 
-/// Query all VS mode models with the given input and display results
-/// show_selection: true for REPL (interactive), false for non-interactive
-pub async fn ask_vs(
-    config: &GlobalConfig,
-    input: Input,
-    _abort_signal: AbortSignal,
-    show_selection: bool,
-) -> Result<()> {
-    // Don't send empty messages (same as regular REPL)
-    if input.is_empty() {
-        return Ok(());
+    match &vs_mode.judge {
+        Some(judge) => println!(
+            "VS mode initialized with {} models (judge: {})",
+            models_list.len(),
+            judge.id()
+        ),
+        None => println!("VS mode initialized with {} models", models_list.len()),
+    }
+    if let Some(synth) = &vs_mode.synth {
+        println!("Multi-select synthesis enabled (synthesizer: {})", synth.id());
     }
 
-    let vs_mode = {
-        let cfg = config.read();
-        cfg.vs_mode.as_ref().cloned()
-    };
+    config.write().vs_mode = Some(vs_mode);
 
-    let Some(vs_mode) = vs_mode else {
-        bail!("Not in VS mode");
-    };
+    Ok(())
+}
 
+/// Collect responses the original, blocking way: one "Generating..." line, then each
+/// response printed in full as soon as it completes. Used for non-interactive callers.
+async fn collect_responses_blocking(
+    config: &GlobalConfig,
+    vs_mode: &VsMode,
+    input: &Input,
+    history: &[VsTurn],
+) -> Result<Vec<VsResponse>> {
     let total_models = vs_mode.models.len();
     let mut responses = Vec::with_capacity(total_models);
 
@@ -138,42 +571,226 @@ pub async fn ask_vs(
         let model = model.clone();
         let input = input.clone();
         let tx = tx.clone();
+        let task_start = std::time::Instant::now();
+        let history = history.to_vec();
 
         tokio::spawn(async move {
-            let result = query_model(input, model.clone()).await;
-            let _ = tx.send((index, model.id().to_string(), result));
+            let result = query_model(input, model.clone(), &history).await;
+            let elapsed_ms = task_start.elapsed().as_millis();
+            let _ = tx.send((index, model.id().to_string(), result, elapsed_ms));
         });
     }
 
     drop(tx);
 
     let mut completed = 0;
-    while let Some((_index, model_id, result)) = rx.recv().await {
+    while let Some((_index, model_id, result, elapsed_ms)) = rx.recv().await {
         completed += 1;
         print_response_header(completed, &model_id);
         display_response(config, &result)?;
-        responses.push((completed, model_id, result));
+        responses.push((completed, model_id, result, elapsed_ms));
     }
 
-    // Show selection menu only in REPL mode (interactive)
-    if show_selection {
-        select_response_without_display(config, &input, &responses)?;
+    Ok(responses)
+}
+
+/// Redraw the single-line preview owned by the model at `index`, out of `total_models`
+/// rows that were printed top to bottom. Each model owns 3 lines: the blank separator and
+/// header line printed by `print_response_header`, plus the blank content placeholder
+/// printed right after it; the preview is written onto that third, content line.
+fn redraw_stream_row(index: usize, total_models: usize, text: &str) -> Result<()> {
+    let rows_below = (3 * (total_models - index) - 2) as u16;
+    let width = terminal::size().map(|(w, _)| w).unwrap_or(80) as usize;
+    let single_line = text.replace('\n', " ");
+    let preview: String = single_line
+        .chars()
+        .rev()
+        .take(width.saturating_sub(1))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    let mut stdout = std::io::stdout();
+    queue!(
+        stdout,
+        cursor::MoveUp(rows_below),
+        cursor::MoveToColumn(0),
+        Clear(ClearType::CurrentLine),
+    )?;
+    write!(stdout, "{preview}")?;
+    queue!(stdout, cursor::MoveDown(rows_below), cursor::MoveToColumn(0))?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Collect responses with concurrent streaming: each model gets a fixed terminal region
+/// (reserved up front right below its header) that is redrawn as chunks arrive, so every
+/// model's output grows in place instead of the slowest model gating all output.
+async fn collect_responses_streaming(
+    config: &GlobalConfig,
+    vs_mode: &VsMode,
+    input: &Input,
+    history: &[VsTurn],
+) -> Result<Vec<VsResponse>> {
+    let total_models = vs_mode.models.len();
+
+    for (index, model) in vs_mode.models.iter().enumerate() {
+        print_response_header(index + 1, model.id());
+        println!();
     }
 
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+    for (index, model) in vs_mode.models.iter().enumerate() {
+        let model = model.clone();
+        let input = input.clone();
+        let tx = tx.clone();
+        let history = history.to_vec();
+        tokio::spawn(async move {
+            query_model_streaming(input, model, index, tx, history).await;
+        });
+    }
+    drop(tx);
+
+    let mut buffers = vec![String::new(); total_models];
+    let mut finished: Vec<Option<(String, Result<String, anyhow::Error>, u128)>> =
+        (0..total_models).map(|_| None).collect();
+    let mut remaining = total_models;
+
+    while remaining > 0 {
+        let Some(event) = rx.recv().await else {
+            break;
+        };
+
+        match event {
+            VsStreamEvent::Chunk { index, delta } => {
+                buffers[index].push_str(&delta);
+                redraw_stream_row(index, total_models, &buffers[index])?;
+            }
+            VsStreamEvent::Done {
+                index,
+                model_id,
+                result,
+                elapsed_ms,
+            } => {
+                let preview = match &result {
+                    Ok(text) => text.clone(),
+                    Err(e) => format!("Error: {e}"),
+                };
+                redraw_stream_row(index, total_models, &preview)?;
+                finished[index] = Some((model_id, result, elapsed_ms));
+                remaining -= 1;
+            }
+        }
+    }
+
+    println!();
+
+    let mut responses = Vec::with_capacity(total_models);
+    for (index, slot) in finished.into_iter().enumerate() {
+        if let Some((model_id, result, elapsed_ms)) = slot {
+            // Show the fully assembled response the same way the blocking path does,
+            // so the rest of ask_vs (selection, judge, synthesis) sees identical text.
+            display_response(config, &result)?;
+            responses.push((index + 1, model_id, result, elapsed_ms));
+        }
+    }
+
+    Ok(responses)
+}
+
+/// Query all VS mode models with the given input and display results
+/// show_selection: true for REPL (interactive), false for non-interactive
+pub async fn ask_vs(
+    config: &GlobalConfig,
+    input: Input,
+    abort_signal: AbortSignal,
+    show_selection: bool,
+) -> Result<()> {
+    run_vs_round(config, input, abort_signal, show_selection, &[]).await?;
     Ok(())
 }
 
+/// Run one full VS round: query every model, display and record the results, then settle on
+/// a winner (manual pick, judge verdict, or synthesis). Returns the response text that was
+/// folded into history, if any, so [`vs_mode_repl`] can accumulate it across turns.
+async fn run_vs_round(
+    config: &GlobalConfig,
+    input: Input,
+    _abort_signal: AbortSignal,
+    show_selection: bool,
+    history: &[VsTurn],
+) -> Result<Option<String>> {
+    // Don't send empty messages (same as regular REPL)
+    if input.is_empty() {
+        return Ok(None);
+    }
+
+    let vs_mode = {
+        let cfg = config.read();
+        cfg.vs_mode.as_ref().cloned()
+    };
 
-/// Handle response selection without displaying results (already printed)
-fn select_response_without_display(
+    let Some(vs_mode) = vs_mode else {
+        bail!("Not in VS mode");
+    };
+
+    // Interactive REPL use gets live streaming regions; non-interactive callers keep the
+    // simpler blocking path, which has no terminal to redraw between calls.
+    let responses = if show_selection {
+        collect_responses_streaming(config, &vs_mode, &input, history).await?
+    } else {
+        collect_responses_blocking(config, &vs_mode, &input, history).await?
+    };
+
+    print_vs_summary_table(&vs_mode, &input, history, &responses);
+
+    let round_id = record_vs_round(input.text(), &responses)
+        .map_err(|e| eprintln!("Failed to record VS round: {e}"))
+        .ok();
+
+    // A configured judge auto-ranks responses and replaces the interactive menu entirely
+    if let Some(judge) = &vs_mode.judge {
+        match run_judge(config, judge, &input, &responses).await {
+            Ok(Some(winner_display_index)) => {
+                let committed = commit_selection(config, &input, &responses, winner_display_index)?;
+                if let Some(round_id) = round_id {
+                    let _ = mark_vs_round_selected(round_id, &[winner_display_index]);
+                }
+                return Ok(committed);
+            }
+            Ok(None) => return Ok(None),
+            Err(e) => {
+                eprintln!("Judge evaluation failed: {e}. Falling back to manual selection.");
+                if show_selection {
+                    return select_response_without_display(config, &input, &responses, round_id).await;
+                }
+                return Ok(None);
+            }
+        }
+    } else if show_selection {
+        // Show selection menu only in REPL mode (interactive)
+        return select_response_without_display(config, &input, &responses, round_id).await;
+    }
+
+    Ok(None)
+}
+
+
+/// Handle response selection without displaying results (already printed).
+/// When VS mode has a synthesizer configured, the user may select several
+/// responses (e.g. `1,3,4`) and have them merged into one answer.
+async fn select_response_without_display(
     config: &GlobalConfig,
     user_input: &Input,
     results: &[VsResponse],
-) -> Result<()> {
+    round_id: Option<i64>,
+) -> Result<Option<String>> {
     println!();
 
     let mut display_order = Vec::new();
-    for (display_index, model_id, result) in results {
+    for (display_index, model_id, result, _elapsed_ms) in results {
         if result.is_ok() {
             display_order.push((display_index, model_id));
             println!("  [{}] {}", display_index, model_id);
@@ -184,43 +801,263 @@ fn select_response_without_display(
         bail!("No valid responses to select from");
     }
 
-    print!("Select response [1-{}] (or 'exit' to quit): ", display_order.len());
+    let synth_model = {
+        let cfg = config.read();
+        cfg.vs_mode.as_ref().and_then(|vs_mode| vs_mode.synth.clone())
+    };
+
+    if synth_model.is_some() {
+        print!(
+            "Select response(s) to merge [1-{}] (comma-separated, or 'exit' to quit): ",
+            display_order.len()
+        );
+    } else {
+        print!("Select response [1-{}] (or 'exit' to quit): ", display_order.len());
+    }
     std::io::stdout().flush()?;
 
     let mut selection_str = String::new();
     std::io::stdin().read_line(&mut selection_str)?;
-    let selection = parse_selection(&selection_str, display_order.len())?;
-    let selected_display_index = display_order[selection - 1].0;
 
+    let Some(synth_model) = synth_model else {
+        let selection = parse_selection(&selection_str, display_order.len())?;
+        let selected_display_index = *display_order[selection - 1].0;
+        let committed = commit_selection(config, user_input, results, selected_display_index)?;
+        if let Some(round_id) = round_id {
+            let _ = mark_vs_round_selected(round_id, &[selected_display_index]);
+        }
+        return Ok(committed);
+    };
+
+    let selections = parse_multi_selection(&selection_str, display_order.len())?;
+    if selections.len() == 1 {
+        let selected_display_index = *display_order[selections[0] - 1].0;
+        let committed = commit_selection(config, user_input, results, selected_display_index)?;
+        if let Some(round_id) = round_id {
+            let _ = mark_vs_round_selected(round_id, &[selected_display_index]);
+        }
+        return Ok(committed);
+    }
+
+    let selected_display_indices: Vec<usize> = selections
+        .iter()
+        .map(|selection| *display_order[selection - 1].0)
+        .collect();
+
+    let merged = synthesize_selection(config, user_input, results, &selected_display_indices, &synth_model).await?;
+    if let Some(round_id) = round_id {
+        let _ = mark_vs_round_selected(round_id, &selected_display_indices);
+    }
+    Ok(Some(merged))
+}
+
+/// Build the prompt asking the synthesizer to merge the hand-picked responses into one answer
+fn build_synthesis_prompt(input: &Input, results: &[VsResponse], selected: &[usize]) -> String {
+    let mut prompt = format!(
+        "Original prompt:\n{}\n\nCombine the best parts of the following responses into a single, coherent answer.\n",
+        input.text()
+    );
+
+    for (display_index, model_id, result, _elapsed_ms) in results {
+        if selected.contains(display_index) {
+            if let Ok(text) = result {
+                prompt.push_str(&format!("\n[{display_index}] ({model_id})\n{text}\n"));
+            }
+        }
+    }
+
+    prompt
+}
+
+/// Merge the selected responses with the synthesizer model and commit the merged answer
+async fn synthesize_selection(
+    config: &GlobalConfig,
+    user_input: &Input,
+    results: &[VsResponse],
+    selected_display_indices: &[usize],
+    synth_model: &Model,
+) -> Result<String> {
+    let prompt = build_synthesis_prompt(user_input, results, selected_display_indices);
+    let synth_input = input_with_text(user_input, prompt);
+    let merged = query_model(synth_input, synth_model.clone(), &[]).await?;
+
+    println!();
+    config.read().print_markdown(&merged)?;
+
+    let mut cfg = config.write();
+    cfg.after_chat_completion(user_input, &merged, &[])?;
+    if let Some(session) = &mut cfg.session {
+        session.set_model(synth_model.clone());
+    }
+    drop(cfg);
+
+    Ok(merged)
+}
+
+/// Commit the response at `selected_display_index` to conversation history and make its
+/// model the session's active one. Shared by manual selection and judge-driven selection.
+/// Returns the committed response text, if the selected result was `Ok`.
+fn commit_selection(
+    config: &GlobalConfig,
+    user_input: &Input,
+    results: &[VsResponse],
+    selected_display_index: usize,
+) -> Result<Option<String>> {
     // Find the result by display index
-    let (_, _, result) = results.iter()
-        .find(|(idx, _, _)| *idx == *selected_display_index)
+    let (_, model_id, result, _elapsed_ms) = results.iter()
+        .find(|(idx, _, _, _)| *idx == selected_display_index)
         .ok_or_else(|| anyhow::anyhow!("Selected response not found"))?;
 
-    if let Ok(response) = result {
-        // Get the selected model
-        let selected_model = {
-            let cfg = config.read();
-            let vs_mode = cfg.vs_mode.as_ref().unwrap();
-
-            // Find the original model index by matching the display index
-            let original_index = results.iter()
-                .position(|(idx, _, _)| *idx == *selected_display_index)
-                .unwrap_or(0);
-
-            vs_mode.models
-                .get(original_index)
-                .cloned()
-                .unwrap_or_else(|| cfg.model.clone())
-        };
+    let Ok(response) = result else {
+        return Ok(None);
+    };
 
-        // Add both user prompt and selected response to conversation history
-        let mut cfg = config.write();
-        cfg.after_chat_completion(user_input, response.as_str(), &[])?;
+    // Get the selected model by the id the result actually came from, not its position in
+    // `results` — that position reflects arrival order from `collect_responses_blocking`'s
+    // mpsc channel, not `vs_mode.models` order, so indexing into `vs_mode.models` by it can
+    // resolve to the wrong model whenever responses arrive out of sequence.
+    let selected_model = {
+        let cfg = config.read();
+        let vs_mode = cfg.vs_mode.as_ref().unwrap();
+
+        vs_mode.models
+            .iter()
+            .find(|m| m.id() == model_id)
+            .cloned()
+            .unwrap_or_else(|| cfg.model.clone())
+    };
 
-        // Update the session's model to the selected one
-        if let Some(session) = &mut cfg.session {
-            session.set_model(selected_model);
+    // Add both user prompt and selected response to conversation history
+    let mut cfg = config.write();
+    cfg.after_chat_completion(user_input, response.as_str(), &[])?;
+
+    // Update the session's model to the selected one
+    if let Some(session) = &mut cfg.session {
+        session.set_model(selected_model);
+    }
+
+    Ok(Some(response.clone()))
+}
+
+/// Remove the model at display position `arg` (1-based) from the race
+fn drop_vs_model(config: &GlobalConfig, arg: &str) -> Result<()> {
+    let position: usize = arg
+        .trim()
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Usage: drop <n>"))?;
+
+    let mut cfg = config.write();
+    let vs_mode = cfg.vs_mode.as_mut().ok_or_else(|| anyhow::anyhow!("Not in VS mode"))?;
+
+    if position < 1 || position > vs_mode.models.len() {
+        bail!("No model at position {position}");
+    }
+    if vs_mode.models.len() <= 2 {
+        bail!("VS mode requires at least 2 models");
+    }
+
+    let removed = vs_mode.models.remove(position - 1);
+    println!("Dropped {} from the race", removed.id());
+    Ok(())
+}
+
+/// Bring a model into the race mid-session
+async fn add_vs_model(config: &GlobalConfig, model_id: &str) -> Result<()> {
+    let model_id = model_id.trim();
+    if model_id.is_empty() {
+        bail!("Usage: add <model_id>");
+    }
+    let model = Model::retrieve_model(&config.read(), model_id, crate::ModelType::Chat)?;
+
+    let mut cfg = config.write();
+    let vs_mode = cfg.vs_mode.as_mut().ok_or_else(|| anyhow::anyhow!("Not in VS mode"))?;
+    println!("Added {} to the race", model.id());
+    vs_mode.models.push(model);
+    Ok(())
+}
+
+/// Print a short list of past recorded VS rounds, most recent first
+fn print_vs_round_history(limit: usize) -> Result<()> {
+    let rounds = list_vs_rounds(limit)?;
+    if rounds.is_empty() {
+        println!("No recorded VS rounds yet");
+        return Ok(());
+    }
+    for round in rounds {
+        let prompt_preview: String = round.prompt.chars().take(60).collect();
+        println!("[{}] {}", round.id, prompt_preview);
+    }
+    Ok(())
+}
+
+/// Parse a `show <id>` argument and re-print that recorded round
+fn show_vs_round_by_arg(config: &GlobalConfig, arg: &str) -> Result<()> {
+    let round_id: i64 = arg
+        .trim()
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Usage: show <id>"))?;
+    show_vs_round(config, round_id)
+}
+
+/// Run a persistent VS REPL: the response that wins each round (manually, via judge, or via
+/// synthesis) is folded into a shared history so every subsequent prompt is re-broadcast to
+/// all `vs_mode.models` with the full conversation so far, rather than a single-shot query.
+/// Besides `exit`/`quit`, the prompt also accepts `drop <n>` to remove a model from the race,
+/// `add <model_id>` to bring one in, `again` to re-run the last prompt, `history` to list past
+/// recorded rounds, and `show <id>` to re-print one of them.
+pub async fn vs_mode_repl(config: &GlobalConfig, abort_signal: AbortSignal, seed_input: Input) -> Result<()> {
+    let mut history: Vec<VsTurn> = Vec::new();
+    let mut current_input = seed_input;
+    let mut first_round = true;
+
+    loop {
+        if first_round {
+            first_round = false;
+        } else {
+            print!("\nvs> ");
+            std::io::stdout().flush()?;
+
+            let mut line = String::new();
+            if std::io::stdin().read_line(&mut line)? == 0 {
+                break;
+            }
+            let trimmed = line.trim();
+
+            if trimmed.eq_ignore_ascii_case("exit") || trimmed.eq_ignore_ascii_case("quit") {
+                break;
+            } else if trimmed.is_empty() {
+                continue;
+            } else if let Some(arg) = trimmed.strip_prefix("drop ") {
+                if let Err(e) = drop_vs_model(config, arg) {
+                    eprintln!("{e}");
+                }
+                continue;
+            } else if let Some(arg) = trimmed.strip_prefix("add ") {
+                if let Err(e) = add_vs_model(config, arg).await {
+                    eprintln!("{e}");
+                }
+                continue;
+            } else if trimmed.eq_ignore_ascii_case("history") {
+                if let Err(e) = print_vs_round_history(20) {
+                    eprintln!("{e}");
+                }
+                continue;
+            } else if let Some(arg) = trimmed.strip_prefix("show ") {
+                if let Err(e) = show_vs_round_by_arg(config, arg) {
+                    eprintln!("{e}");
+                }
+                continue;
+            } else if trimmed.eq_ignore_ascii_case("again") {
+                // Re-run `current_input` unchanged below
+            } else {
+                current_input = input_with_text(&current_input, trimmed.to_string());
+            }
+        }
+
+        match run_vs_round(config, current_input.clone(), abort_signal.clone(), true, &history).await {
+            Ok(Some(response)) => history.push((current_input.text().to_string(), response)),
+            Ok(None) => {}
+            Err(e) => eprintln!("{e}"),
         }
     }
 
@@ -233,3 +1070,75 @@ impl Config {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_json_object_finds_object_amid_prose() {
+        let raw = "Sure, here's my verdict:\n{\"winner\": 2, \"ranking\": [2, 1], \"rationale\": \"clearer\"}\nHope that helps!";
+        let json = extract_json_object(raw).unwrap();
+        assert_eq!(json, "{\"winner\": 2, \"ranking\": [2, 1], \"rationale\": \"clearer\"}");
+    }
+
+    #[test]
+    fn extract_json_object_returns_none_without_braces() {
+        assert!(extract_json_object("the second response is best").is_none());
+    }
+
+    #[test]
+    fn judge_verdict_parses_from_extracted_json() {
+        let raw = "{\"winner\": 1, \"ranking\": [1, 2], \"rationale\": \"more concise\"}";
+        let json = extract_json_object(raw).unwrap();
+        let verdict: JudgeVerdict = serde_json::from_str(json).unwrap();
+        assert_eq!(verdict.winner, 1);
+        assert_eq!(verdict.ranking, vec![1, 2]);
+        assert_eq!(verdict.rationale, "more concise");
+    }
+
+    #[test]
+    fn judge_verdict_fails_to_parse_malformed_json() {
+        let raw = "{\"winner\": 1, \"ranking\": [1, 2]"; // missing rationale and closing brace
+        // With no closing brace there is no object to extract, so no verdict comes out
+        let verdict = extract_json_object(raw).map(serde_json::from_str::<JudgeVerdict>);
+        assert!(!matches!(verdict, Some(Ok(_))));
+    }
+
+    #[test]
+    fn extract_flag_value_pulls_flag_and_strips_it() {
+        let mut args = "gpt-4,claude-3 --judge gpt-4 --synth claude-3".to_string();
+        let judge = extract_flag_value(&mut args, "--judge");
+        assert_eq!(judge.as_deref(), Some("gpt-4"));
+        assert_eq!(args.trim(), "gpt-4,claude-3 --synth claude-3");
+    }
+
+    #[test]
+    fn extract_flag_value_returns_none_when_flag_absent() {
+        let mut args = "gpt-4,claude-3".to_string();
+        assert_eq!(extract_flag_value(&mut args, "--judge"), None);
+        assert_eq!(args, "gpt-4,claude-3");
+    }
+
+    #[test]
+    fn extract_flag_value_ignores_substring_matches() {
+        let mut args = "my--judgemaster,claude-3 --synth claude-3".to_string();
+        assert_eq!(extract_flag_value(&mut args, "--judge"), None);
+        assert_eq!(args, "my--judgemaster,claude-3 --synth claude-3");
+    }
+
+    #[test]
+    fn parse_multi_selection_parses_comma_separated_indices() {
+        assert_eq!(parse_multi_selection("1,3,4", 4).unwrap(), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn parse_multi_selection_rejects_out_of_range_index() {
+        assert!(parse_multi_selection("1,9", 4).is_err());
+    }
+
+    #[test]
+    fn parse_multi_selection_rejects_empty_input() {
+        assert!(parse_multi_selection("", 4).is_err());
+    }
+}